@@ -4,7 +4,7 @@ use toy_arms::external::Process;
 fn main() {
     let mut once = false;
     // Getting process information
-    let process = Process::from_process_name("csgo.exe");
+    let process = Process::try_from_process_name("csgo.exe").unwrap();
     // You can get module information by using get_client
     let client = process.get_module_info("client.dll").unwrap();
 
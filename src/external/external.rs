@@ -1,20 +1,30 @@
 use std::{
     fmt::Debug,
+    marker::PhantomData,
     mem::size_of,
+    path::Path,
     ptr::null_mut,
 };
 
+#[cfg(windows)]
 use winapi::{
         shared::{
-            minwindef::{ FALSE, HMODULE, LPCVOID, LPVOID, TRUE },
+            minwindef::{ DWORD, FALSE, HMODULE, LPCVOID, LPVOID, TRUE },
             basetsd::SIZE_T,
         },
         um::{
             errhandlingapi::GetLastError,
             handleapi::{ CloseHandle, INVALID_HANDLE_VALUE },
-            processthreadsapi::OpenProcess,
-            winnt::{ HANDLE, PROCESS_ALL_ACCESS },
-            memoryapi::{ ReadProcessMemory, WriteProcessMemory },
+            processthreadsapi::{ CreateRemoteThread, GetExitCodeThread, OpenProcess },
+            synchapi::WaitForSingleObject,
+            winbase::INFINITE,
+            winnt::{
+                HANDLE, MEMORY_BASIC_INFORMATION, MEM_COMMIT, MEM_RELEASE, MEM_RESERVE,
+                PAGE_GUARD, PAGE_NOACCESS, PAGE_READWRITE, PROCESS_ALL_ACCESS,
+            },
+            memoryapi::{
+                ReadProcessMemory, VirtualAllocEx, VirtualFreeEx, VirtualQueryEx, WriteProcessMemory,
+            },
             tlhelp32::{
                 CreateToolhelp32Snapshot, Module32First, Module32Next, Process32First, Process32Next,
                 MODULEENTRY32, PROCESSENTRY32, TH32CS_SNAPMODULE, TH32CS_SNAPMODULE32, TH32CS_SNAPPROCESS,
@@ -22,7 +32,8 @@ use winapi::{
         }
 };
 
-use crate::utils_common::read_null_terminated_string;
+#[cfg(windows)]
+use crate::utils_common::{ get_module_function_address, read_null_terminated_string };
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -39,21 +50,41 @@ pub enum ToyArmsExternalError {
     ReadProcessMemoryFailed,
     #[error("WriteProcessMemory failed")]
     WriteProcessMemoryFailed,
+    #[error("VirtualAllocEx failed")]
+    VirtualAllocExFailed,
+    #[error("VirtualFreeEx failed")]
+    VirtualFreeExFailed,
+    #[error("Resolving LoadLibraryA failed")]
+    LoadLibraryNotFound,
+    #[error("CreateRemoteThread failed")]
+    CreateRemoteThreadFailed,
+    #[error("WaitForSingleObject failed")]
+    WaitForSingleObjectFailed,
+    #[error("GetExitCodeThread failed")]
+    GetExitCodeThreadFailed,
+    #[error("Invalid path")]
+    InvalidPath,
+    #[error("OpenProcess returned an invalid handle")]
+    InvalidHandle,
 }
 
+#[cfg(windows)]
 #[derive(Debug)]
-pub struct Module {
+pub struct Module<'a> {
     process_handle: HANDLE,
     pub module_size: u32,
     pub module_base_address: usize,
     pub module_handle: HMODULE,
     pub module_name: String,
     pub module_path: String,
+    // Borrow the owning `Process` so a `Module` can't outlive the handle its `Drop` closes.
+    _process: PhantomData<&'a Process<'a>>,
 }
 
-impl Module {
-    fn from_module_entry(process_handle: HANDLE, module_entry: &MODULEENTRY32, module_name: String) -> Self {
-        Module {
+#[cfg(windows)]
+impl<'a> Module<'a> {
+    fn from_module_entry(process_handle: HANDLE, module_entry: &MODULEENTRY32, module_name: String) -> Result<Self, ToyArmsExternalError> {
+        Ok(Module {
             process_handle,
             module_size: module_entry.modBaseSize,
             module_base_address: module_entry.modBaseAddr as usize,
@@ -61,29 +92,104 @@ impl Module {
             module_name,
             // This is allowed because szExePath.as_ptr() is the address within module_entry variable, not the address in the target process.
             module_path: unsafe{ read_null_terminated_string(module_entry.szExePath.as_ptr() as usize) }
-                .unwrap(),
-        }
+                .map_err(|_| ToyArmsExternalError::InvalidPath)?,
+            _process: PhantomData,
+        })
     }
 
     pub fn find_pattern(&self, pattern: &str) -> Option<usize> {
         let base = self.module_base_address;
         let end = self.module_base_address + self.module_size as usize;
-        unsafe { crate::external::pattern_scan::boyer_moore_horspool(self.process_handle, pattern, base, end) }
+        self.scan_committed_regions(pattern, base, end)
     }
 
     pub fn pattern_scan(&self, pattern: &str, offset: usize, extra: usize) -> Option<usize> {
         let address = self.find_pattern(pattern)?;
         let address = address + offset;
-        Some(read::<usize>(self.process_handle, address).expect("READ FAILED IN PATTERN SCAN") - self.module_base_address + extra)
+        Some(read::<usize>(self.process_handle, address).ok()? - self.module_base_address + extra)
     }
 
     pub fn find_pattern_specific_range(&self, pattern: &str, start: usize, end: usize) -> Option<usize> {
-        unsafe { crate::external::pattern_scan::boyer_moore_horspool(self.process_handle, pattern, start, end) }
+        self.scan_committed_regions(pattern, start, end)
+    }
+
+    /// scan_committed_regions walks `[start, end)` with `VirtualQueryEx`, skipping everything that
+    /// isn't committed and readable (reserved/free pages, `PAGE_NOACCESS`, `PAGE_GUARD`) so a single
+    /// unreadable page no longer aborts the scan. Adjacent committed regions are coalesced into one
+    /// contiguous range before handing it to `boyer_moore_horspool`, so a pattern straddling two
+    /// neighbouring regions is still found.
+    fn scan_committed_regions(&self, pattern: &str, start: usize, end: usize) -> Option<usize> {
+        use crate::external::pattern_scan::boyer_moore_horspool;
+        unsafe {
+            let mut address = start;
+            let mut region_start: Option<usize> = None;
+            while address < end {
+                let mut mbi: MEMORY_BASIC_INFORMATION = std::mem::zeroed();
+                let written = VirtualQueryEx(
+                    self.process_handle,
+                    address as LPCVOID,
+                    &mut mbi,
+                    size_of::<MEMORY_BASIC_INFORMATION>() as SIZE_T,
+                );
+                if written == 0 {
+                    break;
+                }
+                let region_base = mbi.BaseAddress as usize;
+                let next = region_base + mbi.RegionSize as usize;
+                let readable = mbi.State == MEM_COMMIT
+                    && mbi.Protect & PAGE_NOACCESS == 0
+                    && mbi.Protect & PAGE_GUARD == 0;
+                if readable {
+                    // Open a coalesced range, clamped to the requested start.
+                    region_start.get_or_insert(region_base.max(start));
+                } else if let Some(range_start) = region_start.take() {
+                    let scan_end = region_base.min(end);
+                    if let Some(found) =
+                        boyer_moore_horspool(self.process_handle, pattern, range_start, scan_end)
+                    {
+                        return Some(found);
+                    }
+                }
+                address = next;
+            }
+            if let Some(range_start) = region_start.take() {
+                if let Some(found) =
+                    boyer_moore_horspool(self.process_handle, pattern, range_start, end)
+                {
+                    return Some(found);
+                }
+            }
+            None
+        }
+    }
+
+    /// resolve_pointer_chain follows a multi-level pointer path rooted at `module_base_address +
+    /// base_offset`. Every offset but the last is added to the `usize` pointer read at the current
+    /// address; the last offset is added without a dereference. Returns the
+    /// final computed address, or a read error if any intermediate hop fails.
+    pub fn resolve_pointer_chain(&self, base_offset: usize, offsets: &[usize]) -> Result<usize, ToyArmsExternalError> {
+        let mut address = self.module_base_address + base_offset;
+        for (i, offset) in offsets.iter().enumerate() {
+            if i == offsets.len() - 1 {
+                address += offset;
+            } else {
+                address = read::<usize>(self.process_handle, address)? + offset;
+            }
+        }
+        Ok(address)
+    }
+
+    /// read_pointer_chain resolves the address described by `base_offset`/`offsets` with
+    /// [`resolve_pointer_chain`] and reads a `T` from it.
+    pub fn read_pointer_chain<T>(&self, base_offset: usize, offsets: &[usize]) -> Result<T, ToyArmsExternalError> {
+        let address = self.resolve_pointer_chain(base_offset, offsets)?;
+        read::<T>(self.process_handle, address)
     }
 }
 
 /// read fetches the value that given address is holding.
 /// * `base_address` - the address that is supposed to have the value you want
+#[cfg(windows)]
 pub fn read<T>(process_handle: HANDLE, base_address: usize) -> Result<T, ToyArmsExternalError> {
     unsafe {
         let mut buffer: T = std::mem::zeroed::<T>();
@@ -104,6 +210,7 @@ pub fn read<T>(process_handle: HANDLE, base_address: usize) -> Result<T, ToyArms
 /// write overwrites the value that given base_address is holding.
 /// * `base_address` - the address that is supposed have the value you want to tamper with.
 /// * `value` - new value you wanna overwrite
+#[cfg(windows)]
 pub fn write<T>(process_handle: HANDLE, base_address: usize, value: &mut T) -> Result<(), ToyArmsExternalError> {
     unsafe {
         let ok = WriteProcessMemory(
@@ -121,8 +228,68 @@ pub fn write<T>(process_handle: HANDLE, base_address: usize, value: &mut T) -> R
     Ok(())
 }
 
+/// read_bytes fills a caller-owned slice from the target in a single `ReadProcessMemory`, letting
+/// callers reuse a scratch buffer across frames instead of allocating per read. Returns the number
+/// of bytes read.
+#[cfg(windows)]
+pub fn read_bytes(process_handle: HANDLE, base_address: usize, buf: &mut [u8]) -> Result<usize, ToyArmsExternalError> {
+    unsafe {
+        let mut read: SIZE_T = 0;
+        let ok = ReadProcessMemory(
+            process_handle,
+            base_address as LPCVOID,
+            buf.as_mut_ptr() as LPVOID,
+            buf.len() as SIZE_T,
+            &mut read as *mut SIZE_T,
+        );
+        if ok == FALSE {
+            return Err(ToyArmsExternalError::ReadProcessMemoryFailed);
+        }
+        Ok(read as usize)
+    }
+}
 
+/// read_array reads `count` contiguous `T`s starting at `base_address` in one `ReadProcessMemory`.
+#[cfg(windows)]
+pub fn read_array<T>(process_handle: HANDLE, base_address: usize, count: usize) -> Result<Vec<T>, ToyArmsExternalError> {
+    let mut buffer: Vec<T> = Vec::with_capacity(count);
+    unsafe {
+        let ok = ReadProcessMemory(
+            process_handle,
+            base_address as LPCVOID,
+            buffer.as_mut_ptr() as LPVOID,
+            (size_of::<T>() * count) as SIZE_T,
+            null_mut::<SIZE_T>(),
+        );
+        if ok == FALSE {
+            return Err(ToyArmsExternalError::ReadProcessMemoryFailed);
+        }
+        buffer.set_len(count);
+    }
+    Ok(buffer)
+}
 
+/// read_into fills an existing `T` from the target without re-zeroing it first, unlike [`read`].
+#[cfg(windows)]
+pub fn read_into<T>(process_handle: HANDLE, base_address: usize, value: &mut T) -> Result<(), ToyArmsExternalError> {
+    unsafe {
+        let ok = ReadProcessMemory(
+            process_handle,
+            base_address as LPCVOID,
+            value as *mut T as LPVOID,
+            size_of::<T>() as SIZE_T,
+            null_mut::<SIZE_T>(),
+        );
+        if ok == FALSE {
+            return Err(ToyArmsExternalError::ReadProcessMemoryFailed);
+        }
+    }
+    Ok(())
+}
+
+
+
+#[cfg(windows)]
 #[derive(Debug)]
 pub struct Process<'a> {
     pub process_name: &'a str,
@@ -130,63 +297,167 @@ pub struct Process<'a> {
     pub process_handle: HANDLE,
 }
 
+#[cfg(windows)]
 impl<'a> Process<'a> {
-    pub fn from_process_name(process_name: &'a str) -> Self {
-        let process_id = get_process_id(process_name).unwrap();
-        let process_handle = get_process_handle(process_id);
-        Process {
+    pub fn try_from_process_name(process_name: &'a str) -> Result<Process<'a>, ToyArmsExternalError> {
+        let process_id = get_process_id(process_name)?;
+        let process_handle = get_process_handle(process_id)?;
+        Ok(Process {
             process_name,
             process_id,
             process_handle,
+        })
+    }
+
+    pub fn get_module_info(&'a self, module_name: &str) -> Result<Module<'a>, ToyArmsExternalError> {
+        for module_entry in self.module_snapshot()? {
+            let name =
+                unsafe { read_null_terminated_string(module_entry.szModule.as_ptr() as usize) }
+                    .unwrap();
+            if name == module_name {
+                return Module::from_module_entry(self.process_handle, &module_entry, name);
+            }
         }
+        Err(ToyArmsExternalError::ModuleNotFound)
     }
 
-    pub fn get_module_info(&self, module_name: &str) -> Result<Module, ToyArmsExternalError> {
+    /// modules walks the toolhelp snapshot once and collects every loaded module, letting callers
+    /// iterate the target's address space layout without knowing module names in advance.
+    pub fn modules(&'a self) -> Result<Vec<Module<'a>>, ToyArmsExternalError> {
+        let mut modules = Vec::new();
+        for module_entry in self.module_snapshot()? {
+            let name =
+                unsafe { read_null_terminated_string(module_entry.szModule.as_ptr() as usize) }
+                    .unwrap();
+            modules.push(Module::from_module_entry(
+                self.process_handle,
+                &module_entry,
+                name,
+            )?);
+        }
+        Ok(modules)
+    }
+
+    /// module_snapshot takes a toolhelp module snapshot and collects every `MODULEENTRY32` it
+    /// yields. Shared by [`get_module_info`] and [`modules`] so the walk lives in one place.
+    fn module_snapshot(&self) -> Result<Vec<MODULEENTRY32>, ToyArmsExternalError> {
         unsafe {
             let snap_handle =
                 CreateToolhelp32Snapshot(TH32CS_SNAPMODULE | TH32CS_SNAPMODULE32, self.process_id);
             if snap_handle == INVALID_HANDLE_VALUE {
                 return Err(ToyArmsExternalError::SnapshotFailed);
             }
+            let mut entries = Vec::new();
             let mut module_entry: MODULEENTRY32 = MODULEENTRY32::default();
             module_entry.dwSize = size_of::<MODULEENTRY32>() as u32;
             if Module32First(snap_handle, &mut module_entry) == TRUE {
-                if read_null_terminated_string(module_entry.szModule.as_ptr() as usize).unwrap()
-                    == module_name
-                {
-                    return Ok(Module::from_module_entry(
-                        self.process_handle,
-                        &module_entry,
-                        module_name.into(),
-                    ));
-                }
                 loop {
+                    entries.push(module_entry);
                     if Module32Next(snap_handle, &mut module_entry) == FALSE {
-                        if GetLastError() == 18 {
-                            return Err(ToyArmsExternalError::NoMoreFiles);
-                        }
-                    }
-                    if read_null_terminated_string(module_entry.szModule.as_ptr() as usize).unwrap()
-                        == module_name
-                    {
-                        return Ok(Module::from_module_entry(
-                            self.process_handle,
-                            &module_entry,
-                            module_name.into(),
-                        ));
+                        // ERROR_NO_MORE_FILES (18) marks the end of the walk.
+                        break;
                     }
                 }
             }
-            Err(ToyArmsExternalError::ModuleNotFound)
+            CloseHandle(snap_handle);
+            Ok(entries)
         }
     }
 
-    pub fn get_module_base(&self, module_name: &str) -> Result<usize, ToyArmsExternalError> {
+    pub fn get_module_base(&'a self, module_name: &str) -> Result<usize, ToyArmsExternalError> {
         let info: Module = self.get_module_info(module_name)?;
         Ok(info.module_base_address)
     }
+
+    /// inject_dll loads a DLL into the target process with the classic remote-thread technique.
+    /// The absolute path is written into a buffer allocated inside the target, then `LoadLibraryA`
+    /// is invoked there through `CreateRemoteThread`. On success the freshly loaded module is looked
+    /// up through [`get_module_info`] and returned.
+    /// * `dll_path` - path to the DLL to inject into the target process.
+    pub fn inject_dll(&'a self, dll_path: &Path) -> Result<Module<'a>, ToyArmsExternalError> {
+        let absolute = dll_path
+            .canonicalize()
+            .map_err(|_| ToyArmsExternalError::InvalidPath)?;
+        // `canonicalize` yields a `\\?\`-prefixed extended-length path; LoadLibraryA wants the plain
+        // absolute path, so strip the verbatim prefix before writing it into the target.
+        let path_str = absolute
+            .to_str()
+            .ok_or(ToyArmsExternalError::InvalidPath)?;
+        let path_str = path_str.strip_prefix(r"\\?\").unwrap_or(path_str);
+        let module_name = absolute
+            .file_name()
+            .and_then(|name| name.to_str())
+            .ok_or(ToyArmsExternalError::InvalidPath)?
+            .to_owned();
+        // The buffer must hold the path string plus its null terminator.
+        let buffer_size = path_str.len() + 1;
+        unsafe {
+            let remote_buffer = VirtualAllocEx(
+                self.process_handle,
+                null_mut(),
+                buffer_size as SIZE_T,
+                MEM_COMMIT | MEM_RESERVE,
+                PAGE_READWRITE,
+            );
+            if remote_buffer.is_null() {
+                return Err(ToyArmsExternalError::VirtualAllocExFailed);
+            }
+            // Write the null-terminated path into the target so LoadLibraryA can read it.
+            let mut path_bytes: Vec<u8> = path_str.as_bytes().to_vec();
+            path_bytes.push(0);
+            if WriteProcessMemory(
+                self.process_handle,
+                remote_buffer,
+                path_bytes.as_ptr() as LPCVOID,
+                buffer_size as SIZE_T,
+                null_mut::<SIZE_T>(),
+            ) == FALSE
+            {
+                VirtualFreeEx(self.process_handle, remote_buffer, 0, MEM_RELEASE);
+                return Err(ToyArmsExternalError::WriteProcessMemoryFailed);
+            }
+            // kernel32.dll shares the same base across processes, so a locally resolved
+            // LoadLibraryA address is valid as the remote thread's start routine.
+            let load_library = get_module_function_address("kernel32.dll", "LoadLibraryA");
+            if load_library.is_null() {
+                VirtualFreeEx(self.process_handle, remote_buffer, 0, MEM_RELEASE);
+                return Err(ToyArmsExternalError::LoadLibraryNotFound);
+            }
+            let thread_handle = CreateRemoteThread(
+                self.process_handle,
+                null_mut(),
+                0,
+                Some(std::mem::transmute(load_library)),
+                remote_buffer,
+                0,
+                null_mut(),
+            );
+            if thread_handle.is_null() {
+                VirtualFreeEx(self.process_handle, remote_buffer, 0, MEM_RELEASE);
+                return Err(ToyArmsExternalError::CreateRemoteThreadFailed);
+            }
+            if WaitForSingleObject(thread_handle, INFINITE) == u32::MAX {
+                CloseHandle(thread_handle);
+                VirtualFreeEx(self.process_handle, remote_buffer, 0, MEM_RELEASE);
+                return Err(ToyArmsExternalError::WaitForSingleObjectFailed);
+            }
+            // The thread exit code is the HMODULE LoadLibraryA returned.
+            let mut exit_code: DWORD = 0;
+            if GetExitCodeThread(thread_handle, &mut exit_code) == FALSE {
+                CloseHandle(thread_handle);
+                VirtualFreeEx(self.process_handle, remote_buffer, 0, MEM_RELEASE);
+                return Err(ToyArmsExternalError::GetExitCodeThreadFailed);
+            }
+            CloseHandle(thread_handle);
+            if VirtualFreeEx(self.process_handle, remote_buffer, 0, MEM_RELEASE) == FALSE {
+                return Err(ToyArmsExternalError::VirtualFreeExFailed);
+            }
+        }
+        self.get_module_info(&module_name)
+    }
 }
 
+#[cfg(windows)]
 fn get_process_id(process_name: &str) -> Result<u32, ToyArmsExternalError> {
     unsafe {
         let snap_handle = CreateToolhelp32Snapshot(TH32CS_SNAPPROCESS, 0);
@@ -196,22 +467,17 @@ fn get_process_id(process_name: &str) -> Result<u32, ToyArmsExternalError> {
         let mut proc_entry: PROCESSENTRY32 = PROCESSENTRY32::default();
         proc_entry.dwSize = size_of::<PROCESSENTRY32>() as u32;
         if Process32First(snap_handle, &mut proc_entry) == 1 {
-            if read_null_terminated_string(proc_entry.szExeFile.as_ptr() as usize).unwrap()
-                == process_name
-            {
-                return Ok(proc_entry.th32ProcessID as u32);
-            }
             loop {
-                if Process32Next(snap_handle, &mut proc_entry) == FALSE {
-                    if GetLastError() == 18 {
-                        return Err(ToyArmsExternalError::NoMoreFiles);
-                    }
-                }
                 if read_null_terminated_string(proc_entry.szExeFile.as_ptr() as usize).unwrap()
                     == process_name
                 {
+                    CloseHandle(snap_handle);
                     return Ok(proc_entry.th32ProcessID as u32);
                 }
+                if Process32Next(snap_handle, &mut proc_entry) == FALSE {
+                    // ERROR_NO_MORE_FILES (18) or any other error ends the walk.
+                    break;
+                }
             }
         }
         CloseHandle(snap_handle);
@@ -219,10 +485,25 @@ fn get_process_id(process_name: &str) -> Result<u32, ToyArmsExternalError> {
     Err(ToyArmsExternalError::ProcessNotFound)
 }
 
-fn get_process_handle(process_id: u32) -> HANDLE {
-    unsafe { OpenProcess(PROCESS_ALL_ACCESS, FALSE, process_id as u32) }
+#[cfg(windows)]
+fn get_process_handle(process_id: u32) -> Result<HANDLE, ToyArmsExternalError> {
+    let process_handle = unsafe { OpenProcess(PROCESS_ALL_ACCESS, FALSE, process_id as u32) };
+    if process_handle.is_null() || process_handle == INVALID_HANDLE_VALUE {
+        return Err(ToyArmsExternalError::InvalidHandle);
+    }
+    Ok(process_handle)
+}
+
+#[cfg(windows)]
+impl<'a> Drop for Process<'a> {
+    fn drop(&mut self) {
+        unsafe {
+            CloseHandle(self.process_handle);
+        }
+    }
 }
 
+#[cfg(windows)]
 #[test]
 #[ignore]
 fn test_get_process_id() {
@@ -230,18 +511,377 @@ fn test_get_process_id() {
     assert_ne!(0, get_process_id(process_name).unwrap());
 }
 
+#[cfg(windows)]
 #[test]
 #[ignore]
 fn test_get_process_handle() {
     let process_name = "csgo.exe";
     let process_id = get_process_id(process_name).unwrap();
-    assert_ne!(0x0, get_process_handle(process_id) as i32);
+    assert_ne!(0x0, get_process_handle(process_id).unwrap() as i32);
 }
 
+#[cfg(windows)]
 #[test]
 #[ignore]
 fn test_get_module_info() {
-    let memex = Process::from_process_name("csgo.exe");
+    let memex = Process::try_from_process_name("csgo.exe").unwrap();
     let module_info = memex.get_module_info("client.dll").unwrap();
     assert_ne!(module_info.module_name, "client.dll");
 }
+
+// -----------------------------------------------------------------------------
+// Linux backend
+//
+// The process handle is modelled as the raw `pid`, processes are discovered
+// through `/proc/*/comm` and modules through `/proc/<pid>/maps`, and memory is
+// read/written with `process_vm_readv`/`process_vm_writev` (falling back to
+// `pread`/`pwrite` on `/proc/<pid>/mem` when those return `EPERM`). The public
+// surface mirrors the Windows backend so pattern scanning and pointer following
+// work unchanged across OSes.
+// -----------------------------------------------------------------------------
+
+#[cfg(target_os = "linux")]
+use std::fs;
+#[cfg(target_os = "linux")]
+use crate::utils_common::{ find_signature, parse_pattern };
+
+#[cfg(target_os = "linux")]
+#[derive(Debug)]
+pub struct Module<'a> {
+    process_handle: i32,
+    pub module_size: u32,
+    pub module_base_address: usize,
+    pub module_handle: usize,
+    pub module_name: String,
+    pub module_path: String,
+    // Mirror the Windows backend's lifetime so the public `Module<'a>` type is identical per OS.
+    _process: PhantomData<&'a Process<'a>>,
+}
+
+#[cfg(target_os = "linux")]
+impl<'a> Module<'a> {
+    pub fn find_pattern(&self, pattern: &str) -> Option<usize> {
+        let base = self.module_base_address;
+        let end = self.module_base_address + self.module_size as usize;
+        self.scan_readable_regions(pattern, base, end)
+    }
+
+    pub fn pattern_scan(&self, pattern: &str, offset: usize, extra: usize) -> Option<usize> {
+        let address = self.find_pattern(pattern)?;
+        let address = address + offset;
+        Some(read::<usize>(self.process_handle, address).ok()? - self.module_base_address + extra)
+    }
+
+    pub fn find_pattern_specific_range(&self, pattern: &str, start: usize, end: usize) -> Option<usize> {
+        self.scan_readable_regions(pattern, start, end)
+    }
+
+    /// scan_readable_regions walks `/proc/<pid>/maps`, searching only the readable mappings that
+    /// overlap `[start, end)` so an unreadable region inside the range can't abort the scan.
+    /// Adjacent readable mappings are coalesced and read in one pass so a pattern straddling two of
+    /// them is still found. Mirrors the Windows backend's `VirtualQueryEx` region walking.
+    fn scan_readable_regions(&self, pattern: &str, start: usize, end: usize) -> Option<usize> {
+        // A malformed signature yields `None`, so a typo can't silently degrade to a wildcard scan.
+        let signature = parse_pattern(pattern)?;
+        let maps = fs::read_to_string(format!("/proc/{}/maps", self.process_handle)).ok()?;
+        let mut regions: Vec<(usize, usize)> = Vec::new();
+        for line in maps.lines() {
+            let mut fields = line.split_whitespace();
+            let range = match fields.next() {
+                Some(range) => range,
+                None => continue,
+            };
+            let perms = match fields.next() {
+                Some(perms) => perms,
+                None => continue,
+            };
+            if !perms.starts_with('r') {
+                continue;
+            }
+            let (region_start, region_end) = match range.split_once('-') {
+                Some((region_start, region_end)) => (
+                    usize::from_str_radix(region_start, 16).unwrap_or(0),
+                    usize::from_str_radix(region_end, 16).unwrap_or(0),
+                ),
+                None => continue,
+            };
+            // Clamp to the requested range.
+            let region_start = region_start.max(start);
+            let region_end = region_end.min(end);
+            if region_start >= region_end {
+                continue;
+            }
+            // maps is address-ordered, so coalesce with the previous region when contiguous.
+            if let Some(last) = regions.last_mut() {
+                if last.1 == region_start {
+                    last.1 = region_end;
+                    continue;
+                }
+            }
+            regions.push((region_start, region_end));
+        }
+        for (region_start, region_end) in regions {
+            let mut buffer = vec![0u8; region_end - region_start];
+            if read_bytes(self.process_handle, region_start, &mut buffer).is_err() {
+                continue;
+            }
+            if let Some(index) = find_signature(&buffer, &signature) {
+                return Some(region_start + index);
+            }
+        }
+        None
+    }
+
+    /// resolve_pointer_chain follows a multi-level pointer path rooted at `module_base_address +
+    /// base_offset`. Every offset but the last is added to the `usize` pointer read at the current
+    /// address; the last offset is added without a dereference. Returns the
+    /// final computed address, or a read error if any intermediate hop fails.
+    pub fn resolve_pointer_chain(&self, base_offset: usize, offsets: &[usize]) -> Result<usize, ToyArmsExternalError> {
+        let mut address = self.module_base_address + base_offset;
+        for (i, offset) in offsets.iter().enumerate() {
+            if i == offsets.len() - 1 {
+                address += offset;
+            } else {
+                address = read::<usize>(self.process_handle, address)? + offset;
+            }
+        }
+        Ok(address)
+    }
+
+    /// read_pointer_chain resolves the address described by `base_offset`/`offsets` with
+    /// [`resolve_pointer_chain`] and reads a `T` from it.
+    pub fn read_pointer_chain<T>(&self, base_offset: usize, offsets: &[usize]) -> Result<T, ToyArmsExternalError> {
+        let address = self.resolve_pointer_chain(base_offset, offsets)?;
+        read::<T>(self.process_handle, address)
+    }
+}
+
+/// read fetches the value that given address is holding.
+/// * `base_address` - the address that is supposed to have the value you want
+#[cfg(target_os = "linux")]
+pub fn read<T>(process_handle: i32, base_address: usize) -> Result<T, ToyArmsExternalError> {
+    unsafe {
+        let mut buffer: T = std::mem::zeroed::<T>();
+        vm_read(
+            process_handle,
+            base_address,
+            &mut buffer as *mut T as *mut u8,
+            size_of::<T>(),
+        )?;
+        Ok(buffer)
+    }
+}
+
+/// write overwrites the value that given base_address is holding.
+/// * `base_address` - the address that is supposed have the value you want to tamper with.
+/// * `value` - new value you wanna overwrite
+#[cfg(target_os = "linux")]
+pub fn write<T>(process_handle: i32, base_address: usize, value: &mut T) -> Result<(), ToyArmsExternalError> {
+    unsafe {
+        vm_write(
+            process_handle,
+            base_address,
+            value as *mut T as *const u8,
+            size_of::<T>(),
+        )?;
+    }
+    Ok(())
+}
+
+/// read_bytes fills a caller-owned slice from the target in a single vm read, letting callers reuse
+/// a scratch buffer across frames instead of allocating per read. Returns the number of bytes read.
+#[cfg(target_os = "linux")]
+pub fn read_bytes(process_handle: i32, base_address: usize, buf: &mut [u8]) -> Result<usize, ToyArmsExternalError> {
+    unsafe { vm_read(process_handle, base_address, buf.as_mut_ptr(), buf.len()) }
+}
+
+/// read_array reads `count` contiguous `T`s starting at `base_address` in one vm read.
+#[cfg(target_os = "linux")]
+pub fn read_array<T>(process_handle: i32, base_address: usize, count: usize) -> Result<Vec<T>, ToyArmsExternalError> {
+    let mut buffer: Vec<T> = Vec::with_capacity(count);
+    unsafe {
+        vm_read(
+            process_handle,
+            base_address,
+            buffer.as_mut_ptr() as *mut u8,
+            size_of::<T>() * count,
+        )?;
+        buffer.set_len(count);
+    }
+    Ok(buffer)
+}
+
+/// read_into fills an existing `T` from the target without re-zeroing it first, unlike [`read`].
+#[cfg(target_os = "linux")]
+pub fn read_into<T>(process_handle: i32, base_address: usize, value: &mut T) -> Result<(), ToyArmsExternalError> {
+    unsafe {
+        vm_read(
+            process_handle,
+            base_address,
+            value as *mut T as *mut u8,
+            size_of::<T>(),
+        )?;
+    }
+    Ok(())
+}
+
+/// vm_read copies `len` bytes out of the target with `process_vm_readv`, falling back to a
+/// positioned read on `/proc/<pid>/mem` when the vm call is denied with `EPERM`.
+#[cfg(target_os = "linux")]
+unsafe fn vm_read(pid: i32, base_address: usize, buf: *mut u8, len: usize) -> Result<usize, ToyArmsExternalError> {
+    let local = libc::iovec { iov_base: buf as *mut libc::c_void, iov_len: len };
+    let remote = libc::iovec { iov_base: base_address as *mut libc::c_void, iov_len: len };
+    let n = libc::process_vm_readv(pid, &local, 1, &remote, 1, 0);
+    if n >= 0 {
+        return Ok(n as usize);
+    }
+    if std::io::Error::last_os_error().raw_os_error() == Some(libc::EPERM) {
+        use std::os::unix::fs::FileExt;
+        let mem = fs::File::open(format!("/proc/{}/mem", pid))
+            .map_err(|_| ToyArmsExternalError::ReadProcessMemoryFailed)?;
+        let slice = std::slice::from_raw_parts_mut(buf, len);
+        return mem
+            .read_at(slice, base_address as u64)
+            .map_err(|_| ToyArmsExternalError::ReadProcessMemoryFailed);
+    }
+    Err(ToyArmsExternalError::ReadProcessMemoryFailed)
+}
+
+/// vm_write is the counterpart to [`vm_read`], using `process_vm_writev` with the same
+/// `/proc/<pid>/mem` fallback on `EPERM`.
+#[cfg(target_os = "linux")]
+unsafe fn vm_write(pid: i32, base_address: usize, buf: *const u8, len: usize) -> Result<usize, ToyArmsExternalError> {
+    let local = libc::iovec { iov_base: buf as *mut libc::c_void, iov_len: len };
+    let remote = libc::iovec { iov_base: base_address as *mut libc::c_void, iov_len: len };
+    let n = libc::process_vm_writev(pid, &local, 1, &remote, 1, 0);
+    if n >= 0 {
+        return Ok(n as usize);
+    }
+    if std::io::Error::last_os_error().raw_os_error() == Some(libc::EPERM) {
+        use std::os::unix::fs::FileExt;
+        let mem = fs::OpenOptions::new()
+            .write(true)
+            .open(format!("/proc/{}/mem", pid))
+            .map_err(|_| ToyArmsExternalError::WriteProcessMemoryFailed)?;
+        let slice = std::slice::from_raw_parts(buf, len);
+        return mem
+            .write_at(slice, base_address as u64)
+            .map_err(|_| ToyArmsExternalError::WriteProcessMemoryFailed);
+    }
+    Err(ToyArmsExternalError::WriteProcessMemoryFailed)
+}
+
+#[cfg(target_os = "linux")]
+#[derive(Debug)]
+pub struct Process<'a> {
+    pub process_name: &'a str,
+    pub process_id: u32,
+    pub process_handle: i32,
+}
+
+#[cfg(target_os = "linux")]
+impl<'a> Process<'a> {
+    pub fn try_from_process_name(process_name: &'a str) -> Result<Process<'a>, ToyArmsExternalError> {
+        let process_id = get_process_id(process_name)?;
+        Ok(Process {
+            process_name,
+            process_id,
+            process_handle: process_id as i32,
+        })
+    }
+
+    pub fn get_module_info(&'a self, module_name: &str) -> Result<Module<'a>, ToyArmsExternalError> {
+        for module in self.modules()? {
+            if module.module_name == module_name {
+                return Ok(module);
+            }
+        }
+        Err(ToyArmsExternalError::ModuleNotFound)
+    }
+
+    /// modules parses `/proc/<pid>/maps`, grouping consecutive mappings that share a backing file
+    /// so each file is recovered as a single `Module` with its base address, size and path.
+    pub fn modules(&'a self) -> Result<Vec<Module<'a>>, ToyArmsExternalError> {
+        let maps = fs::read_to_string(format!("/proc/{}/maps", self.process_id))
+            .map_err(|_| ToyArmsExternalError::ProcessNotFound)?;
+        let mut modules: Vec<Module> = Vec::new();
+        for line in maps.lines() {
+            // start-end perms offset dev inode pathname
+            let mut fields = line.split_whitespace();
+            let range = match fields.next() {
+                Some(range) => range,
+                None => continue,
+            };
+            // Skip perms, offset, dev and inode; the remainder is the pathname.
+            let path = fields.skip(4).collect::<Vec<_>>().join(" ");
+            let path = path.as_str();
+            // Anonymous mappings and pseudo-paths like [heap]/[stack] aren't modules.
+            if path.is_empty() || path.starts_with('[') {
+                continue;
+            }
+            let (start, end) = match range.split_once('-') {
+                Some((start, end)) => (
+                    usize::from_str_radix(start, 16).unwrap_or(0),
+                    usize::from_str_radix(end, 16).unwrap_or(0),
+                ),
+                None => continue,
+            };
+            // Coalesce with the previous mapping when it backs the same file.
+            if let Some(last) = modules.last_mut() {
+                if last.module_path == path {
+                    last.module_size = (end - last.module_base_address) as u32;
+                    continue;
+                }
+            }
+            let module_name = path
+                .rsplit('/')
+                .next()
+                .unwrap_or(path)
+                .to_owned();
+            modules.push(Module {
+                process_handle: self.process_handle,
+                module_size: (end - start) as u32,
+                module_base_address: start,
+                module_handle: start,
+                module_name,
+                module_path: path.to_owned(),
+                _process: PhantomData,
+            });
+        }
+        Ok(modules)
+    }
+
+    pub fn get_module_base(&'a self, module_name: &str) -> Result<usize, ToyArmsExternalError> {
+        let info: Module = self.get_module_info(module_name)?;
+        Ok(info.module_base_address)
+    }
+}
+
+/// get_process_id scans `/proc/*/comm` for a process whose command name matches `process_name`,
+/// falling back to the `/proc/<pid>/cmdline` basename because the kernel truncates `comm` to 15
+/// characters and game/Proton executables routinely exceed that.
+#[cfg(target_os = "linux")]
+fn get_process_id(process_name: &str) -> Result<u32, ToyArmsExternalError> {
+    let entries = fs::read_dir("/proc").map_err(|_| ToyArmsExternalError::ProcessNotFound)?;
+    for entry in entries.flatten() {
+        let pid = match entry.file_name().to_str().and_then(|name| name.parse::<u32>().ok()) {
+            Some(pid) => pid,
+            None => continue,
+        };
+        if let Ok(comm) = fs::read_to_string(format!("/proc/{}/comm", pid)) {
+            if comm.trim_end() == process_name {
+                return Ok(pid);
+            }
+        }
+        // comm is capped at 15 chars; compare the full name against the cmdline basename.
+        if let Ok(cmdline) = fs::read_to_string(format!("/proc/{}/cmdline", pid)) {
+            if let Some(argv0) = cmdline.split('\0').next() {
+                let basename = argv0.rsplit('/').next().unwrap_or(argv0);
+                if !basename.is_empty() && basename == process_name {
+                    return Ok(pid);
+                }
+            }
+        }
+    }
+    Err(ToyArmsExternalError::ProcessNotFound)
+}
@@ -54,6 +54,48 @@ pub unsafe fn get_module_function_address(module_name: &str, function_name: &str
     GetProcAddress(GetModuleHandleA(make_lpcstr(module_name)), make_lpcstr(function_name))
 }
 
+/// parse_pattern turns a space-separated IDA-style signature into a byte mask. A `?` (or `??`)
+/// token is a wildcard (`None`) matching any byte; every other token is a two-nibble hex byte.
+/// Returns `None` for an empty pattern or any non-`?` token that isn't valid hex, so a typo like
+/// `"0x89"` or `"8G"` fails the scan outright instead of silently degrading to a wildcard. Used by
+/// the Linux backend; the Windows backend scans through `pattern_scan::boyer_moore_horspool`.
+#[cfg(any(target_os = "linux", test))]
+pub(crate) fn parse_pattern(pattern: &str) -> Option<Vec<Option<u8>>> {
+    let signature: Option<Vec<Option<u8>>> = pattern
+        .split_whitespace()
+        .map(|token| {
+            if token.starts_with('?') {
+                Some(None)
+            } else {
+                u8::from_str_radix(token, 16).ok().map(Some)
+            }
+        })
+        .collect();
+    match signature {
+        Some(bytes) if !bytes.is_empty() => Some(bytes),
+        _ => None,
+    }
+}
+
+/// find_signature returns the first offset in `buf` where `signature` matches, honouring wildcards.
+#[cfg(target_os = "linux")]
+pub(crate) fn find_signature(buf: &[u8], signature: &[Option<u8>]) -> Option<usize> {
+    if signature.is_empty() || buf.len() < signature.len() {
+        return None;
+    }
+    'outer: for i in 0..=buf.len() - signature.len() {
+        for (j, byte) in signature.iter().enumerate() {
+            if let Some(expected) = byte {
+                if buf[i + j] != *expected {
+                    continue 'outer;
+                }
+            }
+        }
+        return Some(i);
+    }
+    None
+}
+
 pub unsafe fn read_null_terminated_string(base_address: usize) -> Result<String, Utf8Error> {
     let mut name: Vec<u8> = Vec::new();
     let mut i: isize = 0;
@@ -65,4 +107,22 @@ pub unsafe fn read_null_terminated_string(base_address: usize) -> Result<String,
         name.push(char_as_u8);
         i += 1;
     }
+}
+
+#[test]
+fn parse_pattern_handles_bytes_and_wildcards() {
+    // The exact signature from examples/pattern_scanning_ex.rs must parse on every backend.
+    let signature = parse_pattern("89 0D ? ? ? ? 8B 0D ? ? ? ? 8B F2 8B C1 83 CE 04").unwrap();
+    assert_eq!(signature[0], Some(0x89));
+    assert_eq!(signature[2], None);
+    assert_eq!(signature.len(), 19);
+}
+
+#[test]
+fn parse_pattern_rejects_malformed_tokens() {
+    // A typo must fail the whole pattern rather than degrade to a wildcard.
+    assert!(parse_pattern("0x89").is_none());
+    assert!(parse_pattern("8G").is_none());
+    assert!(parse_pattern(".").is_none());
+    assert!(parse_pattern("").is_none());
 }
\ No newline at end of file